@@ -0,0 +1,711 @@
+// Copyright (C) 2024 Quickwit, Inc.
+//
+// Quickwit is offered under the AGPL v3.0 and as commercial software.
+// For commercial licensing, contact us at hello@quickwit.io.
+//
+// AGPL:
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as
+// published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use quickwit_actors::{Actor, ActorContext, ActorExitStatus, Handler, Mailbox};
+use quickwit_common::pubsub::EventBroker;
+use quickwit_metastore::{
+    IndexMetadataResponseExt, ListSplitsRequestExt, ListSplitsResponseExt, SplitMetadata,
+    SplitState,
+};
+use quickwit_proto::metastore::{
+    DeleteDeleteTasksRequest, DeleteQuery, DeleteTask, IndexMetadataRequest,
+    ListDeleteTasksRequest, ListSplitsRequest, MetastoreService, MetastoreServiceClient,
+    UpdateSplitsDeleteOpstampRequest,
+};
+use quickwit_proto::types::IndexUid;
+use quickwit_search::SearchJobPlacer;
+use quickwit_storage::Storage;
+use tracing::{error, info, warn};
+
+use super::delete_task_service::{
+    DeleteQueryPreviewCounts, DeleteTaskService, DeleteTaskStatus, ReportDeleteTaskStatus,
+    WakeUpDeleteTaskPipeline,
+};
+
+/// Interval at which the pipeline checks the metastore for delete tasks it hasn't applied yet.
+/// `WakeUpDeleteTaskPipeline` lets `DeleteTaskService` short-circuit this wait as soon as a new
+/// delete task is created, so this interval only has to catch whatever a missed event leaves
+/// behind.
+const APPLY_DELETE_TASKS_INTERVAL: Duration = if cfg!(any(test, feature = "testsuite")) {
+    Duration::from_millis(200)
+} else {
+    Duration::from_secs(60)
+};
+
+/// Applies delete tasks created against a single index: for every delete task still pending, it
+/// counts the documents the query matches in each candidate split and bumps the delete opstamp of
+/// every candidate split, matched or not, so they are not considered again by this task. This does not yet rewrite a split's content to physically drop the
+/// matched documents -- see `delete_task_service_dir` below for that gap.
+pub struct DeleteTaskPipeline {
+    index_uid: IndexUid,
+    metastore: MetastoreServiceClient,
+    search_job_placer: SearchJobPlacer,
+    index_storage: Arc<dyn Storage>,
+    #[allow(dead_code)] // Reserved for split rewriting, which stages files under this directory.
+    delete_task_service_dir: PathBuf,
+    #[allow(dead_code)]
+    max_concurrent_split_uploads: usize,
+    #[allow(dead_code)] // Kept so the pipeline can itself publish events in the future.
+    event_broker: EventBroker,
+    delete_task_service_mailbox: Mailbox<DeleteTaskService>,
+    /// Opstamps of delete tasks that `DeleteTaskService` asked us to abort. This is shared with
+    /// (and mutated directly by) `DeleteTaskService`'s `CancelDeleteTask` handler instead of
+    /// going through a message to this actor's own mailbox: a message could only be dequeued once
+    /// the current handler call returns, by which point a task being applied in that same call
+    /// would already be done. Checked between splits so we never leave one half-rewritten.
+    aborted_opstamps: Arc<Mutex<HashSet<u64>>>,
+    /// Opstamps of delete tasks that have already reached a terminal status. The metastore has no
+    /// "last applied opstamp" cursor, so `list_delete_tasks` always returns every task ever
+    /// created for this index; without this, a long-lived delete task would be re-fetched and
+    /// re-searched on every tick forever.
+    terminal_opstamps: HashSet<u64>,
+}
+
+impl DeleteTaskPipeline {
+    pub fn new(
+        index_uid: IndexUid,
+        metastore: MetastoreServiceClient,
+        search_job_placer: SearchJobPlacer,
+        index_storage: Arc<dyn Storage>,
+        delete_task_service_dir: PathBuf,
+        max_concurrent_split_uploads: usize,
+        event_broker: EventBroker,
+        delete_task_service_mailbox: Mailbox<DeleteTaskService>,
+        aborted_opstamps: Arc<Mutex<HashSet<u64>>>,
+    ) -> Self {
+        Self {
+            index_uid,
+            metastore,
+            search_job_placer,
+            index_storage,
+            delete_task_service_dir,
+            max_concurrent_split_uploads,
+            event_broker,
+            delete_task_service_mailbox,
+            aborted_opstamps,
+            terminal_opstamps: HashSet::new(),
+        }
+    }
+
+    /// Persists a cancellation by removing the task from the metastore, so a restart doesn't
+    /// pick it back up, then reports the terminal status back to `DeleteTaskService`.
+    async fn persist_cancellation(&mut self, opstamp: u64) {
+        if let Err(error) = self
+            .metastore
+            .delete_delete_tasks(DeleteDeleteTasksRequest {
+                index_uid: self.index_uid.to_string(),
+                opstamps: vec![opstamp],
+            })
+            .await
+        {
+            error!(
+                error=%error,
+                index_uid=%self.index_uid,
+                opstamp,
+                "failed to persist delete task cancellation to the metastore"
+            );
+        }
+        self.report_status(opstamp, DeleteTaskStatus::Cancelled)
+            .await;
+    }
+
+    async fn report_status(&mut self, opstamp: u64, status: DeleteTaskStatus) {
+        if status.is_terminal() {
+            self.terminal_opstamps.insert(opstamp);
+        }
+        if let Err(error) = self
+            .delete_task_service_mailbox
+            .send_message(ReportDeleteTaskStatus {
+                index_uid: self.index_uid.clone(),
+                opstamp,
+                status,
+            })
+            .await
+        {
+            error!(
+                error=%error,
+                index_uid=%self.index_uid,
+                opstamp,
+                "failed to report delete task status back to DeleteTaskService"
+            );
+        }
+    }
+
+    /// Lists the splits a delete query could match: published splits that have not yet
+    /// incorporated a delete with at least this opstamp, and that intersect the query's time
+    /// range, if any.
+    async fn list_candidate_splits(
+        &mut self,
+        delete_query: &DeleteQuery,
+        opstamp: u64,
+    ) -> anyhow::Result<Vec<SplitMetadata>> {
+        let mut list_splits_request = ListSplitsRequest::try_from_index_uid(self.index_uid.clone())
+            .with_split_state(SplitState::Published)
+            .with_delete_opstamp_lt(opstamp);
+        if let Some(start_timestamp) = delete_query.start_timestamp {
+            list_splits_request = list_splits_request.with_time_range_start_gte(start_timestamp);
+        }
+        if let Some(end_timestamp) = delete_query.end_timestamp {
+            list_splits_request = list_splits_request.with_time_range_end_lt(end_timestamp);
+        }
+        let splits = self
+            .metastore
+            .list_splits(list_splits_request)
+            .await?
+            .deserialize_splits()?
+            .into_iter()
+            .map(|split| split.split_metadata)
+            .collect();
+        Ok(splits)
+    }
+
+    /// Whether `opstamp` is still present among the index's delete tasks in the metastore. Used
+    /// to re-validate a task immediately before starting work on it, since a cancellation can have
+    /// deleted its row after it was captured in an earlier `list_delete_tasks` snapshot.
+    async fn task_still_pending(&mut self, opstamp: u64) -> anyhow::Result<bool> {
+        let delete_tasks = self
+            .metastore
+            .list_delete_tasks(ListDeleteTasksRequest::new(self.index_uid.clone(), 0))
+            .await?
+            .delete_tasks;
+        Ok(delete_tasks
+            .iter()
+            .any(|delete_task| delete_task.opstamp == opstamp))
+    }
+
+    /// Applies every delete task currently pending for this index, one at a time.
+    async fn apply_pending_delete_tasks(&mut self) -> anyhow::Result<()> {
+        let delete_tasks = self
+            .metastore
+            .list_delete_tasks(ListDeleteTasksRequest::new(self.index_uid.clone(), 0))
+            .await?
+            .delete_tasks;
+        for delete_task in delete_tasks {
+            // The metastore has no cursor to exclude tasks we have already finished with, so it
+            // hands back every delete task ever created for this index on every tick: skip the
+            // ones we already know are done, before doing any metastore/search work for them.
+            if self.terminal_opstamps.contains(&delete_task.opstamp) {
+                continue;
+            }
+            let is_aborted = self
+                .aborted_opstamps
+                .lock()
+                .unwrap()
+                .remove(&delete_task.opstamp);
+            if is_aborted {
+                self.persist_cancellation(delete_task.opstamp).await;
+                continue;
+            }
+            // `delete_tasks` above is a snapshot taken once at the top of this tick. A task still
+            // `Enqueued` at that point never sets `aborted_opstamps` if it is cancelled afterwards
+            // (see `needs_pipeline_abort_signal` in `delete_task_service`), so a cancellation that
+            // lands while this loop is still working through earlier tasks would otherwise go
+            // unnoticed until this task's turn, clobbering the already-recorded `Cancelled` status
+            // with `Succeeded`. `CancelDeleteTask` unconditionally deletes the task's metastore row
+            // regardless of its status, so re-checking for its continued existence right before
+            // starting work on it catches exactly this race.
+            if !self.task_still_pending(delete_task.opstamp).await? {
+                continue;
+            }
+            if let Err(error) = self.apply_delete_task(delete_task.clone()).await {
+                warn!(
+                    error=%error,
+                    index_uid=%self.index_uid,
+                    opstamp = delete_task.opstamp,
+                    "failed to apply delete task"
+                );
+                self.report_status(
+                    delete_task.opstamp,
+                    DeleteTaskStatus::Failed {
+                        error: error.to_string(),
+                    },
+                )
+                .await;
+            }
+        }
+        Ok(())
+    }
+
+    async fn apply_delete_task(&mut self, delete_task: DeleteTask) -> anyhow::Result<()> {
+        let Some(delete_query) = delete_task.delete_query.clone() else {
+            return Ok(());
+        };
+        self.report_status(delete_task.opstamp, DeleteTaskStatus::Processing)
+            .await;
+
+        let candidate_splits = self
+            .list_candidate_splits(&delete_query, delete_task.opstamp)
+            .await?;
+        let matches = count_matching_docs_per_split(
+            &self.index_uid,
+            &delete_query,
+            &candidate_splits,
+            &self.search_job_placer,
+            &self.index_storage,
+        )
+        .await?;
+
+        let mut num_matched_docs = 0u64;
+        let mut marked_split_ids = Vec::new();
+        for split in &candidate_splits {
+            // Honor a cancellation request at the next safe boundary: once we are between two
+            // splits, never mid-rewrite. `aborted_opstamps` can be set concurrently by
+            // `DeleteTaskService`'s `CancelDeleteTask` handler, directly on the shared `Mutex`, so
+            // this is visible immediately rather than on some future tick.
+            let is_aborted = self
+                .aborted_opstamps
+                .lock()
+                .unwrap()
+                .remove(&delete_task.opstamp);
+            if is_aborted {
+                self.persist_cancellation(delete_task.opstamp).await;
+                return Ok(());
+            }
+            // Rewriting a split's content is out of scope here: we only record that this split
+            // has now incorporated deletes up to this opstamp, so it is skipped by later tasks.
+            // This must happen for every candidate split, matched or not: a split this query
+            // didn't match still needs to record that it has "seen" this opstamp, or it (and the
+            // whole task) would be re-listed and re-searched on every future tick forever.
+            self.metastore
+                .update_splits_delete_opstamp(UpdateSplitsDeleteOpstampRequest {
+                    index_uid: self.index_uid.to_string(),
+                    split_ids: vec![split.split_id().to_string()],
+                    delete_opstamp: delete_task.opstamp,
+                })
+                .await?;
+            let num_split_matched_docs = matches.get(split.split_id()).copied().unwrap_or(0);
+            if num_split_matched_docs == 0 {
+                continue;
+            }
+            num_matched_docs += num_split_matched_docs;
+            marked_split_ids.push(split.split_id().to_string());
+        }
+
+        info!(
+            index_uid=%self.index_uid,
+            opstamp = delete_task.opstamp,
+            num_matched_docs,
+            num_splits_marked = marked_split_ids.len(),
+            "applied delete task"
+        );
+        self.report_status(
+            delete_task.opstamp,
+            DeleteTaskStatus::Succeeded {
+                num_matched_docs,
+                num_splits_marked: marked_split_ids.len(),
+            },
+        )
+        .await;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Actor for DeleteTaskPipeline {
+    type ObservableState = ();
+
+    fn observable_state(&self) -> Self::ObservableState {}
+
+    fn name(&self) -> String {
+        "DeleteTaskPipeline".to_string()
+    }
+
+    async fn initialize(&mut self, ctx: &ActorContext<Self>) -> Result<(), ActorExitStatus> {
+        self.handle(ApplyDeleteTasks, ctx).await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct ApplyDeleteTasks;
+
+#[async_trait]
+impl Handler<ApplyDeleteTasks> for DeleteTaskPipeline {
+    type Reply = ();
+
+    async fn handle(
+        &mut self,
+        _: ApplyDeleteTasks,
+        ctx: &ActorContext<Self>,
+    ) -> Result<(), ActorExitStatus> {
+        if let Err(error) = self.apply_pending_delete_tasks().await {
+            error!(error=%error, index_uid=%self.index_uid, "failed to apply pending delete tasks");
+        }
+        ctx.schedule_self_msg(APPLY_DELETE_TASKS_INTERVAL, ApplyDeleteTasks);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<WakeUpDeleteTaskPipeline> for DeleteTaskPipeline {
+    type Reply = ();
+
+    async fn handle(
+        &mut self,
+        _: WakeUpDeleteTaskPipeline,
+        ctx: &ActorContext<Self>,
+    ) -> Result<(), ActorExitStatus> {
+        if let Err(error) = self.apply_pending_delete_tasks().await {
+            error!(error=%error, index_uid=%self.index_uid, "failed to apply pending delete tasks");
+        }
+        Ok(())
+    }
+}
+
+/// Counts, for each candidate split, how many documents `delete_query` matches. Shared by the
+/// real apply path above and [`preview_matching_splits`] below, so a dry-run preview and the
+/// delete it previews agree on what would be affected.
+async fn count_matching_docs_per_split(
+    index_uid: &IndexUid,
+    delete_query: &DeleteQuery,
+    candidate_splits: &[SplitMetadata],
+    search_job_placer: &SearchJobPlacer,
+    index_storage: &Arc<dyn Storage>,
+) -> anyhow::Result<HashMap<String, u64>> {
+    quickwit_search::count_matching_docs_per_split(
+        index_uid,
+        &delete_query.query_ast,
+        delete_query.start_timestamp,
+        delete_query.end_timestamp,
+        candidate_splits,
+        search_job_placer,
+        index_storage,
+    )
+    .await
+}
+
+/// Resolves a dry-run delete query against its candidate splits without rewriting anything:
+/// returns the total and per-split matched document counts, plus the ids of the splits a real
+/// delete would rewrite.
+pub(crate) async fn preview_matching_splits(
+    index_uid: &IndexUid,
+    delete_query: &DeleteQuery,
+    metastore: &mut MetastoreServiceClient,
+    search_job_placer: &SearchJobPlacer,
+    storage_resolver: &quickwit_storage::StorageResolver,
+) -> anyhow::Result<DeleteQueryPreviewCounts> {
+    let index_metadata = metastore
+        .index_metadata(IndexMetadataRequest::for_index_id(
+            index_uid.index_id().to_string(),
+        ))
+        .await?
+        .deserialize_index_metadata()?;
+    let index_config = index_metadata.into_index_config();
+    let index_storage = storage_resolver.resolve(&index_config.index_uri).await?;
+
+    // A preview never has its own opstamp: it considers every published split, regardless of
+    // what has already been deleted from it, since it is only answering "what would a delete
+    // query submitted right now match".
+    let current_max_opstamp = u64::MAX;
+    let list_splits_request = ListSplitsRequest::try_from_index_uid(index_uid.clone())
+        .with_split_state(SplitState::Published)
+        .with_delete_opstamp_lt(current_max_opstamp);
+    let candidate_splits: Vec<SplitMetadata> = metastore
+        .list_splits(list_splits_request)
+        .await?
+        .deserialize_splits()?
+        .into_iter()
+        .map(|split| split.split_metadata)
+        .collect();
+
+    let matched_docs_by_split_id = count_matching_docs_per_split(
+        index_uid,
+        delete_query,
+        &candidate_splits,
+        search_job_placer,
+        &index_storage,
+    )
+    .await?
+    .into_iter()
+    .filter(|(_, num_matched_docs)| *num_matched_docs > 0)
+    .collect::<HashMap<_, _>>();
+
+    let total_matched_docs = matched_docs_by_split_id.values().sum();
+    let split_ids_to_rewrite = matched_docs_by_split_id.keys().cloned().collect();
+    Ok(DeleteQueryPreviewCounts {
+        total_matched_docs,
+        matched_docs_by_split_id,
+        split_ids_to_rewrite,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use quickwit_indexing::TestSandbox;
+    use quickwit_search::{searcher_pool_for_test, MockSearchService};
+    use quickwit_storage::StorageResolver;
+
+    use super::*;
+    use crate::actors::delete_task_service::DeleteTaskService;
+
+    async fn spawn_test_delete_task_service(
+        test_sandbox: &TestSandbox,
+    ) -> (
+        Mailbox<DeleteTaskService>,
+        quickwit_actors::ActorHandle<DeleteTaskService>,
+    ) {
+        let delete_task_service = DeleteTaskService::new(
+            test_sandbox.metastore(),
+            SearchJobPlacer::new(searcher_pool_for_test([(
+                "127.0.0.1:1000",
+                MockSearchService::new(),
+            )])),
+            StorageResolver::unconfigured(),
+            tempfile::tempdir().unwrap().path().to_path_buf(),
+            4,
+            EventBroker::default(),
+        )
+        .await
+        .unwrap();
+        test_sandbox
+            .universe()
+            .spawn_builder()
+            .spawn(delete_task_service)
+    }
+
+    /// Builds a `DeleteTaskPipeline` directly, bypassing the actor system entirely, so its
+    /// methods can be driven one call at a time and its private state inspected directly,
+    /// instead of racing the real 200ms test schedule.
+    async fn build_test_pipeline(
+        test_sandbox: &TestSandbox,
+        delete_task_service_mailbox: Mailbox<DeleteTaskService>,
+        aborted_opstamps: Arc<Mutex<HashSet<u64>>>,
+    ) -> DeleteTaskPipeline {
+        let index_uid = test_sandbox.index_uid();
+        let mut metastore = test_sandbox.metastore();
+        let index_metadata = metastore
+            .index_metadata(IndexMetadataRequest::for_index_id(
+                index_uid.index_id().to_string(),
+            ))
+            .await
+            .unwrap()
+            .deserialize_index_metadata()
+            .unwrap();
+        let index_storage = StorageResolver::unconfigured()
+            .resolve(index_metadata.index_uri())
+            .await
+            .unwrap();
+        DeleteTaskPipeline::new(
+            index_uid,
+            metastore,
+            SearchJobPlacer::new(searcher_pool_for_test([(
+                "127.0.0.1:1001",
+                MockSearchService::new(),
+            )])),
+            index_storage,
+            tempfile::tempdir().unwrap().path().to_path_buf(),
+            4,
+            EventBroker::default(),
+            delete_task_service_mailbox,
+            aborted_opstamps,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_apply_pending_delete_tasks_skips_already_terminal_opstamps() -> anyhow::Result<()>
+    {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-pipeline-skips-terminal-opstamps-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let mut metastore = test_sandbox.metastore();
+        let (service_mailbox, _service_handler) =
+            spawn_test_delete_task_service(&test_sandbox).await;
+        let mut pipeline = build_test_pipeline(
+            &test_sandbox,
+            service_mailbox,
+            Arc::new(Mutex::new(HashSet::new())),
+        )
+        .await;
+
+        let delete_query = DeleteQuery {
+            index_uid: index_uid.to_string(),
+            start_timestamp: None,
+            end_timestamp: None,
+            query_ast: r#"{"type": "MatchAll"}"#.to_string(),
+        };
+        let delete_task = metastore.create_delete_task(delete_query).await.unwrap();
+
+        pipeline.apply_pending_delete_tasks().await?;
+        assert!(pipeline.terminal_opstamps.contains(&delete_task.opstamp));
+        let splits_after_first_tick: Vec<u64> = metastore
+            .clone()
+            .list_splits(ListSplitsRequest::try_from_index_uid(index_uid.clone()))
+            .await?
+            .deserialize_splits()?
+            .into_iter()
+            .map(|split| split.split_metadata.delete_opstamp)
+            .collect();
+
+        // The metastore never removes a terminal task on its own, so a second tick re-lists the
+        // very same task: it must be a no-op, since the opstamp is already known terminal, and no
+        // split's delete opstamp should move again.
+        pipeline.apply_pending_delete_tasks().await?;
+        let splits_after_second_tick: Vec<u64> = metastore
+            .clone()
+            .list_splits(ListSplitsRequest::try_from_index_uid(index_uid.clone()))
+            .await?
+            .deserialize_splits()?
+            .into_iter()
+            .map(|split| split.split_metadata.delete_opstamp)
+            .collect();
+        assert_eq!(splits_after_first_tick, splits_after_second_tick);
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_of_a_processing_task_is_honored_via_shared_state(
+    ) -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-pipeline-cancellation-shared-state-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let mut metastore = test_sandbox.metastore();
+        let (service_mailbox, service_handler) =
+            spawn_test_delete_task_service(&test_sandbox).await;
+        let aborted_opstamps = Arc::new(Mutex::new(HashSet::new()));
+        let mut pipeline =
+            build_test_pipeline(&test_sandbox, service_mailbox, aborted_opstamps.clone()).await;
+
+        let delete_query = DeleteQuery {
+            index_uid: index_uid.to_string(),
+            start_timestamp: None,
+            end_timestamp: None,
+            query_ast: r#"{"type": "MatchAll"}"#.to_string(),
+        };
+        let delete_task = metastore.create_delete_task(delete_query).await.unwrap();
+
+        // Mirror what `apply_delete_task` does on entry, so the task is observably `Processing`
+        // before it is cancelled, the same way a task genuinely mid-flight would be.
+        pipeline
+            .report_status(delete_task.opstamp, DeleteTaskStatus::Processing)
+            .await;
+        let state = service_handler.process_pending_and_observe().await;
+        assert_eq!(
+            state
+                .delete_task_statuses
+                .get(&(index_uid.clone(), delete_task.opstamp))
+                .unwrap()
+                .status,
+            DeleteTaskStatus::Processing
+        );
+
+        // `DeleteTaskService`'s `CancelDeleteTask` handler would mutate this shared `Mutex`
+        // directly and synchronously; it never sends a message to this pipeline's mailbox, so
+        // this reproduces the cancellation without relying on any actor message being dequeued.
+        aborted_opstamps.lock().unwrap().insert(delete_task.opstamp);
+
+        pipeline.apply_pending_delete_tasks().await?;
+
+        assert_eq!(
+            metastore
+                .list_delete_tasks(ListDeleteTasksRequest::new(index_uid.clone(), 0))
+                .await
+                .unwrap()
+                .delete_tasks
+                .len(),
+            0
+        );
+        let state = service_handler.process_pending_and_observe().await;
+        assert_eq!(
+            state
+                .delete_task_statuses
+                .get(&(index_uid.clone(), delete_task.opstamp))
+                .unwrap()
+                .status,
+            DeleteTaskStatus::Cancelled
+        );
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_apply_pending_delete_tasks_skips_task_gone_before_tick() -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-pipeline-skips-cancelled-mid-tick-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let mut metastore = test_sandbox.metastore();
+        let (service_mailbox, service_handler) =
+            spawn_test_delete_task_service(&test_sandbox).await;
+        let mut pipeline = build_test_pipeline(
+            &test_sandbox,
+            service_mailbox,
+            Arc::new(Mutex::new(HashSet::new())),
+        )
+        .await;
+
+        let delete_query = DeleteQuery {
+            index_uid: index_uid.to_string(),
+            start_timestamp: None,
+            end_timestamp: None,
+            query_ast: r#"{"type": "MatchAll"}"#.to_string(),
+        };
+        let task_to_keep = metastore
+            .create_delete_task(delete_query.clone())
+            .await
+            .unwrap();
+        let task_to_cancel = metastore.create_delete_task(delete_query).await.unwrap();
+
+        // Mirror `CancelDeleteTask`'s unconditional metastore deletion for a task that is still
+        // `Enqueued` (so `aborted_opstamps` was never set, per `needs_pipeline_abort_signal`).
+        // The row is removed before the tick below starts, so the tick's `list_delete_tasks`
+        // snapshot never contains it: this checks the outcome a cancellation must have, not the
+        // mid-tick `task_still_pending` recheck itself.
+        metastore
+            .delete_delete_tasks(DeleteDeleteTasksRequest {
+                index_uid: index_uid.to_string(),
+                opstamps: vec![task_to_cancel.opstamp],
+            })
+            .await
+            .unwrap();
+
+        pipeline.apply_pending_delete_tasks().await?;
+
+        assert!(pipeline.terminal_opstamps.contains(&task_to_keep.opstamp));
+        assert!(!pipeline.terminal_opstamps.contains(&task_to_cancel.opstamp));
+        let state = service_handler.process_pending_and_observe().await;
+        assert!(matches!(
+            state
+                .delete_task_statuses
+                .get(&(index_uid.clone(), task_to_keep.opstamp))
+                .unwrap()
+                .status,
+            DeleteTaskStatus::Succeeded { .. }
+        ));
+        // The removed task must never be reported `Succeeded`: its metastore row was gone before
+        // the tick, so it is silently skipped and no status is reported for it at all.
+        assert!(state
+            .delete_task_statuses
+            .get(&(index_uid.clone(), task_to_cancel.opstamp))
+            .is_none());
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+}
@@ -19,48 +19,187 @@
 
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use async_trait::async_trait;
 use quickwit_actors::{Actor, ActorContext, ActorExitStatus, ActorHandle, Handler};
-use quickwit_common::pubsub::EventBroker;
+use quickwit_common::pubsub::{EventBroker, EventSubscriptionHandle};
 use quickwit_common::temp_dir::{self};
 use quickwit_config::IndexConfig;
-use quickwit_metastore::{IndexMetadataResponseExt, ListIndexesMetadataResponseExt};
+use quickwit_metastore::metastore_event_publisher::MetastoreEventPublisher;
+use quickwit_metastore::{
+    DeleteTaskCreatedEvent, IndexCreatedEvent, IndexDeletedEvent, IndexMetadataResponseExt,
+    ListIndexesMetadataResponseExt,
+};
 use quickwit_proto::metastore::{
-    IndexMetadataRequest, ListIndexesMetadataRequest, MetastoreService, MetastoreServiceClient,
+    DeleteDeleteTasksRequest, DeleteQuery, IndexMetadataRequest, ListDeleteTasksRequest,
+    ListIndexesMetadataRequest, MetastoreService, MetastoreServiceClient,
 };
 use quickwit_proto::types::IndexUid;
 use quickwit_search::SearchJobPlacer;
 use quickwit_storage::StorageResolver;
 use serde::Serialize;
+use time::OffsetDateTime;
 use tracing::{error, info, warn};
 
-use super::delete_task_pipeline::DeleteTaskPipeline;
+use super::delete_task_pipeline::{self, DeleteTaskPipeline};
 
 pub const DELETE_SERVICE_TASK_DIR_NAME: &str = "delete_task_service";
 
+// Of the three events `DeleteTaskService` subscribes to (see `initialize`), only
+// `DeleteTaskCreatedEvent` is genuinely event-driven in production: `new` constructs this
+// service's own `MetastoreEventPublisher` and routes `Handler<CreateDeleteTask>` through it.
+// `IndexCreatedEvent`/`IndexDeletedEvent` are not -- nothing outside this service wraps the
+// metastore client behind the production `create_index`/`delete_index` call sites with a
+// `MetastoreEventPublisher`, so those two never fire yet. This interval is the only thing
+// reconciling index creation/deletion until that remaining piece is wired in; widening it now
+// would leave index reconciliation with nothing backing it up.
 const UPDATE_PIPELINES_INTERVAL: Duration = if cfg!(any(test, feature = "testsuite")) {
     Duration::from_millis(200)
 } else {
-    // Each update triggers a call to the metastore. Deletes are not frequent operation and
-    // it's fine to wait a bit before updating the pipelines.
     Duration::from_secs(30)
 };
 
+/// Caps the number of delete task statuses we keep in memory so a long-running service does not
+/// grow the map unbounded. Eviction only ever picks among terminal (finished) tasks, oldest
+/// `finished_at` first, so a task that is still enqueued/processing is never evicted out from
+/// under a client polling for its outcome.
+const MAX_TRACKED_DELETE_TASKS: usize = 1_000;
+
+/// Caps the number of preview statuses we keep in memory, tracked separately from
+/// `MAX_TRACKED_DELETE_TASKS` rather than sharing that budget: a preview is terminal almost as
+/// soon as it is recorded (see `PreviewDeleteQuery`), so a burst of dry-run previews would
+/// otherwise evict real delete-task history far faster than `MAX_TRACKED_DELETE_TASKS` alone
+/// implies.
+const MAX_TRACKED_PREVIEWS: usize = 1_000;
+
+/// Lifecycle status of a single delete task, mirroring the task-store status machine.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub enum DeleteTaskStatus {
+    Enqueued,
+    Processing,
+    /// The query has been matched against every candidate split and each one had its
+    /// `delete_opstamp` bumped accordingly; this does not yet mean any document was physically
+    /// removed from a split's content, which only happens when it is later rewritten (split
+    /// rewriting itself is not implemented yet, see `DeleteTaskPipeline`'s
+    /// `delete_task_service_dir` field doc).
+    Succeeded {
+        num_matched_docs: u64,
+        num_splits_marked: usize,
+    },
+    Failed {
+        error: String,
+    },
+    Cancelled,
+    /// Terminal status of a dry-run preview: no split was rewritten, these are just the counts
+    /// the real delete would affect.
+    Previewed {
+        total_matched_docs: u64,
+        matched_docs_by_split_id: HashMap<String, u64>,
+        split_ids_to_rewrite: Vec<String>,
+    },
+}
+
+impl DeleteTaskStatus {
+    pub(crate) fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            DeleteTaskStatus::Succeeded { .. }
+                | DeleteTaskStatus::Failed { .. }
+                | DeleteTaskStatus::Cancelled
+                | DeleteTaskStatus::Previewed { .. }
+        )
+    }
+}
+
+/// A delete query whose effects are only being previewed: the task id is synthesized locally
+/// (it is not backed by a metastore opstamp) and namespaced away from real delete task ids.
+const PREVIEW_TASK_ID_BIT: u64 = 1 << 63;
+
+/// Aggregate counts produced by [`delete_task_pipeline::preview_matching_splits`] for a
+/// dry-run delete query.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DeleteQueryPreviewCounts {
+    pub total_matched_docs: u64,
+    pub matched_docs_by_split_id: HashMap<String, u64>,
+    pub split_ids_to_rewrite: Vec<String>,
+}
+
+/// Status of a delete task along with the timestamps of its lifecycle transitions.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteTaskStatusInfo {
+    pub status: DeleteTaskStatus,
+    pub created_at: i64,
+    pub started_at: Option<i64>,
+    pub finished_at: Option<i64>,
+}
+
+impl DeleteTaskStatusInfo {
+    fn new(now: i64) -> Self {
+        Self {
+            status: DeleteTaskStatus::Enqueued,
+            created_at: now,
+            started_at: None,
+            finished_at: None,
+        }
+    }
+}
+
+/// A delete task as reported by the metastore, enriched with the status we have locally tracked
+/// for it, if any.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteTaskWithStatus {
+    pub opstamp: u64,
+    pub status: Option<DeleteTaskStatusInfo>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct DeleteTaskServiceState {
     pub num_running_pipelines: usize,
+    /// Status of the most recently seen delete tasks, keyed by `(index_uid, opstamp)`: opstamps
+    /// are per-index sequences (every metastore call that uses one always pairs it with an
+    /// `index_uid`), so two indexes can legitimately report the same opstamp.
+    pub delete_task_statuses: HashMap<(IndexUid, u64), DeleteTaskStatusInfo>,
+    /// Status of the most recently seen delete query previews, keyed by `(index_uid, preview_task_id)`.
+    /// Tracked separately from `delete_task_statuses` so previews and real delete tasks don't
+    /// compete for the same eviction budget, see `MAX_TRACKED_PREVIEWS`.
+    pub preview_statuses: HashMap<(IndexUid, u64), DeleteTaskStatusInfo>,
 }
 
+/// A running [`DeleteTaskPipeline`], along with the out-of-band cancellation signal for it. See
+/// the pipeline's own `aborted_opstamps` field doc for why this is a shared `Mutex` rather than a
+/// mailbox message.
+struct PipelineHandle {
+    actor_handle: ActorHandle<DeleteTaskPipeline>,
+    aborted_opstamps: Arc<Mutex<HashSet<u64>>>,
+}
+
+/// Reconciles delete task pipelines with the metastore, normally on a `UPDATE_PIPELINES_INTERVAL`
+/// poll. See that constant's doc comment for which of the three events this service subscribes to
+/// in `initialize` are actually live in production today.
 pub struct DeleteTaskService {
     metastore: MetastoreServiceClient,
     search_job_placer: SearchJobPlacer,
     storage_resolver: StorageResolver,
     delete_service_task_dir: PathBuf,
-    pipeline_handles_by_index_uid: HashMap<IndexUid, ActorHandle<DeleteTaskPipeline>>,
+    pipeline_handles_by_index_uid: HashMap<IndexUid, PipelineHandle>,
     max_concurrent_split_uploads: usize,
     event_broker: EventBroker,
+    /// Routes `Handler<CreateDeleteTask>`'s metastore call through the event-publishing
+    /// decorator, so the `DeleteTaskCreatedEvent` it publishes and the one this service relies on
+    /// for `Handler<DeleteTaskCreatedEvent>` stay a single code path instead of two that could
+    /// drift apart.
+    delete_task_event_publisher: MetastoreEventPublisher,
+    delete_task_statuses: HashMap<(IndexUid, u64), DeleteTaskStatusInfo>,
+    /// Status of delete query previews, tracked in a map of its own rather than folded into
+    /// `delete_task_statuses`: see `MAX_TRACKED_PREVIEWS`.
+    preview_statuses: HashMap<(IndexUid, u64), DeleteTaskStatusInfo>,
+    // Kept alive for as long as the service runs: dropping a handle unsubscribes it.
+    _index_created_subscription_handle: Option<EventSubscriptionHandle>,
+    _index_deleted_subscription_handle: Option<EventSubscriptionHandle>,
+    _delete_task_created_subscription_handle: Option<EventSubscriptionHandle>,
+    next_preview_task_id: u64,
 }
 
 impl DeleteTaskService {
@@ -75,6 +214,8 @@ impl DeleteTaskService {
         let delete_service_task_path = data_dir_path.join(DELETE_SERVICE_TASK_DIR_NAME);
         let delete_service_task_dir =
             temp_dir::create_or_purge_directory(delete_service_task_path.as_path()).await?;
+        let delete_task_event_publisher =
+            MetastoreEventPublisher::new(metastore.clone(), event_broker.clone());
         Ok(Self {
             metastore,
             search_job_placer,
@@ -83,6 +224,13 @@ impl DeleteTaskService {
             pipeline_handles_by_index_uid: Default::default(),
             max_concurrent_split_uploads,
             event_broker,
+            delete_task_event_publisher,
+            delete_task_statuses: Default::default(),
+            preview_statuses: Default::default(),
+            _index_created_subscription_handle: None,
+            _index_deleted_subscription_handle: None,
+            _delete_task_created_subscription_handle: None,
+            next_preview_task_id: 0,
         })
     }
 }
@@ -94,6 +242,8 @@ impl Actor for DeleteTaskService {
     fn observable_state(&self) -> Self::ObservableState {
         DeleteTaskServiceState {
             num_running_pipelines: self.pipeline_handles_by_index_uid.len(),
+            delete_task_statuses: self.delete_task_statuses.clone(),
+            preview_statuses: self.preview_statuses.clone(),
         }
     }
 
@@ -102,6 +252,22 @@ impl Actor for DeleteTaskService {
     }
 
     async fn initialize(&mut self, ctx: &ActorContext<Self>) -> Result<(), ActorExitStatus> {
+        // These subscriptions fire whenever something publishes into `self.event_broker`, letting
+        // pipeline updates happen immediately instead of waiting for `UPDATE_PIPELINES_INTERVAL`
+        // (see that const's doc comment for which of these three events are actually reachable in
+        // production today, and which still depend on `UpdatePipelines` below).
+        self._index_created_subscription_handle = Some(
+            self.event_broker
+                .subscribe::<IndexCreatedEvent>(ctx.mailbox().clone()),
+        );
+        self._index_deleted_subscription_handle = Some(
+            self.event_broker
+                .subscribe::<IndexDeletedEvent>(ctx.mailbox().clone()),
+        );
+        self._delete_task_created_subscription_handle = Some(
+            self.event_broker
+                .subscribe::<DeleteTaskCreatedEvent>(ctx.mailbox().clone()),
+        );
         self.handle(UpdatePipelines, ctx).await?;
         Ok(())
     }
@@ -131,16 +297,7 @@ impl DeleteTaskService {
 
         // Remove pipelines on deleted indexes.
         for deleted_index_uid in pipeline_index_uids.difference(&index_uids) {
-            info!(
-                deleted_index_id = deleted_index_uid.index_id(),
-                "Remove deleted index from delete task pipelines."
-            );
-            let pipeline_handle = self
-                .pipeline_handles_by_index_uid
-                .remove(deleted_index_uid)
-                .expect("Handle must be present.");
-            // Kill the pipeline, this avoids to wait a long time for a delete operation to finish.
-            pipeline_handle.kill().await;
+            self.remove_pipeline(deleted_index_uid).await;
         }
 
         // Start new pipelines and add them to the handles hashmap.
@@ -159,6 +316,39 @@ impl DeleteTaskService {
         Ok(())
     }
 
+    /// Removes and kills the pipeline for `index_uid`, if one is running.
+    async fn remove_pipeline(&mut self, index_uid: &IndexUid) {
+        let Some(pipeline_handle) = self.pipeline_handles_by_index_uid.remove(index_uid) else {
+            return;
+        };
+        info!(
+            deleted_index_id = index_uid.index_id(),
+            "Remove deleted index from delete task pipelines."
+        );
+        // Kill the pipeline, this avoids to wait a long time for a delete operation to finish.
+        pipeline_handle.actor_handle.kill().await;
+    }
+
+    /// Spawns a pipeline for `index_uid` if one isn't already running.
+    async fn spawn_pipeline_for_index_uid(
+        &mut self,
+        index_uid: &IndexUid,
+        ctx: &ActorContext<Self>,
+    ) -> anyhow::Result<()> {
+        if self.pipeline_handles_by_index_uid.contains_key(index_uid) {
+            return Ok(());
+        }
+        let index_metadata_request =
+            IndexMetadataRequest::for_index_id(index_uid.index_id().to_string());
+        let index_metadata = self
+            .metastore
+            .index_metadata(index_metadata_request)
+            .await?
+            .deserialize_index_metadata()?;
+        let index_config = index_metadata.into_index_config();
+        self.spawn_pipeline(index_config, ctx).await
+    }
+
     pub async fn spawn_pipeline(
         &mut self,
         index_config: IndexConfig,
@@ -173,6 +363,7 @@ impl DeleteTaskService {
             .index_metadata(index_metadata_request)
             .await?
             .deserialize_index_metadata()?;
+        let aborted_opstamps = Arc::new(Mutex::new(HashSet::new()));
         let pipeline = DeleteTaskPipeline::new(
             index_metadata.index_uid.clone(),
             self.metastore.clone(),
@@ -181,12 +372,126 @@ impl DeleteTaskService {
             self.delete_service_task_dir.clone(),
             self.max_concurrent_split_uploads,
             self.event_broker.clone(),
+            ctx.mailbox().clone(),
+            aborted_opstamps.clone(),
         );
         let (_pipeline_mailbox, pipeline_handler) = ctx.spawn_actor().spawn(pipeline);
-        self.pipeline_handles_by_index_uid
-            .insert(index_metadata.index_uid, pipeline_handler);
+        self.pipeline_handles_by_index_uid.insert(
+            index_metadata.index_uid,
+            PipelineHandle {
+                actor_handle: pipeline_handler,
+                aborted_opstamps,
+            },
+        );
         Ok(())
     }
+
+    /// Returns the current status of a delete task or preview, if it is still tracked. `opstamp`
+    /// is only unique within `index_uid`: two different indexes can report the same opstamp.
+    pub fn delete_task_status(
+        &self,
+        index_uid: &IndexUid,
+        opstamp: u64,
+    ) -> Option<DeleteTaskStatusInfo> {
+        self.statuses_map_for(opstamp)
+            .get(&(index_uid.clone(), opstamp))
+            .cloned()
+    }
+
+    /// Enriches a list of opstamps scoped to a single `index_uid` with their currently known
+    /// status, leaving tasks that are not (or no longer) tracked without a status.
+    pub fn enrich_with_statuses(
+        &self,
+        index_uid: &IndexUid,
+        opstamps: impl IntoIterator<Item = u64>,
+    ) -> HashMap<u64, Option<DeleteTaskStatusInfo>> {
+        opstamps
+            .into_iter()
+            .map(|opstamp| (opstamp, self.delete_task_status(index_uid, opstamp)))
+            .collect()
+    }
+
+    /// Allocates a new id for a preview (dry-run) task, distinct from any real delete task
+    /// opstamp.
+    fn next_preview_task_id(&mut self) -> u64 {
+        let preview_task_id = PREVIEW_TASK_ID_BIT | self.next_preview_task_id;
+        self.next_preview_task_id += 1;
+        preview_task_id
+    }
+
+    /// Returns the map a given `opstamp` belongs in: a preview id (see `PREVIEW_TASK_ID_BIT`) is
+    /// tracked in `preview_statuses`, a real delete task opstamp in `delete_task_statuses`.
+    /// `statuses_map_for_mut` also returns the cap that bounds that map: `MAX_TRACKED_PREVIEWS`
+    /// or `MAX_TRACKED_DELETE_TASKS` respectively.
+    fn statuses_map_for(&self, opstamp: u64) -> &HashMap<(IndexUid, u64), DeleteTaskStatusInfo> {
+        if opstamp & PREVIEW_TASK_ID_BIT != 0 {
+            &self.preview_statuses
+        } else {
+            &self.delete_task_statuses
+        }
+    }
+
+    fn statuses_map_for_mut(
+        &mut self,
+        opstamp: u64,
+    ) -> (&mut HashMap<(IndexUid, u64), DeleteTaskStatusInfo>, usize) {
+        if opstamp & PREVIEW_TASK_ID_BIT != 0 {
+            (&mut self.preview_statuses, MAX_TRACKED_PREVIEWS)
+        } else {
+            (&mut self.delete_task_statuses, MAX_TRACKED_DELETE_TASKS)
+        }
+    }
+
+    /// Records `status` for `(index_uid, opstamp)`. A terminal status, once recorded, is never
+    /// overwritten by a later call: `CancelDeleteTask` and a pipeline's own status reports race
+    /// against each other through two different mailboxes with no ordering guarantee, so a
+    /// `Cancelled` recorded just before a pipeline's already-in-flight `Succeeded`/`Failed` report
+    /// arrives (or the reverse) must win and stay put, whichever happened to be recorded first.
+    fn record_delete_task_status(
+        &mut self,
+        index_uid: IndexUid,
+        opstamp: u64,
+        status: DeleteTaskStatus,
+    ) {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let (statuses, max_tracked) = self.statuses_map_for_mut(opstamp);
+        let key = (index_uid.clone(), opstamp);
+        let status_info = statuses
+            .entry(key)
+            .or_insert_with(|| DeleteTaskStatusInfo::new(now));
+        if status_info.status.is_terminal() {
+            warn!(
+                %index_uid,
+                opstamp,
+                existing_status = ?status_info.status,
+                new_status = ?status,
+                "ignoring delete task status report: existing status is already terminal"
+            );
+            return;
+        }
+        if matches!(status, DeleteTaskStatus::Processing) && status_info.started_at.is_none() {
+            status_info.started_at = Some(now);
+        }
+        if status.is_terminal() {
+            status_info.finished_at = Some(now);
+        }
+        status_info.status = status;
+
+        if statuses.len() > max_tracked {
+            // Only terminal tasks are eligible for eviction: a task still enqueued or processing
+            // must stay reachable until it actually finishes, otherwise a poller can't tell
+            // "still running" apart from "never existed". If every tracked task happens to be
+            // non-terminal, the map is simply allowed to grow past the cap for now.
+            let oldest_finished_key = statuses
+                .iter()
+                .filter(|(_, status_info)| status_info.finished_at.is_some())
+                .min_by_key(|(_, status_info)| status_info.finished_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest_finished_key) = oldest_finished_key {
+                statuses.remove(&oldest_finished_key);
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -210,52 +515,465 @@ impl Handler<UpdatePipelines> for DeleteTaskService {
     }
 }
 
+/// Message sent by a [`DeleteTaskPipeline`] to report the current stage of one of the delete
+/// tasks it is working through.
+#[derive(Debug)]
+pub struct ReportDeleteTaskStatus {
+    pub index_uid: IndexUid,
+    pub opstamp: u64,
+    pub status: DeleteTaskStatus,
+}
+
+#[async_trait]
+impl Handler<ReportDeleteTaskStatus> for DeleteTaskService {
+    type Reply = ();
+
+    async fn handle(
+        &mut self,
+        message: ReportDeleteTaskStatus,
+        _ctx: &ActorContext<Self>,
+    ) -> Result<(), ActorExitStatus> {
+        self.record_delete_task_status(message.index_uid, message.opstamp, message.status);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<ListDeleteTasksRequest> for DeleteTaskService {
+    type Reply = anyhow::Result<Vec<DeleteTaskWithStatus>>;
+
+    async fn handle(
+        &mut self,
+        message: ListDeleteTasksRequest,
+        _ctx: &ActorContext<Self>,
+    ) -> Result<Self::Reply, ActorExitStatus> {
+        let reply = async {
+            let index_uid: IndexUid = message.index_uid.clone().into();
+            let delete_tasks = self
+                .metastore
+                .clone()
+                .list_delete_tasks(message)
+                .await?
+                .delete_tasks;
+            let statuses =
+                self.enrich_with_statuses(&index_uid, delete_tasks.iter().map(|task| task.opstamp));
+            let delete_tasks_with_status = delete_tasks
+                .into_iter()
+                .map(|task| DeleteTaskWithStatus {
+                    status: statuses.get(&task.opstamp).cloned().flatten(),
+                    opstamp: task.opstamp,
+                })
+                .collect();
+            Ok(delete_tasks_with_status)
+        }
+        .await;
+        Ok(reply)
+    }
+}
+
+/// Creates a new delete task for `index_uid` and records it as `Enqueued` right away. Callers
+/// should go through this message rather than calling `create_delete_task` on the metastore
+/// directly: doing the latter leaves the task with no tracked status at all until its pipeline
+/// happens to report `Processing`, which is indistinguishable from an opstamp nothing ever knew
+/// about.
+#[derive(Debug)]
+pub struct CreateDeleteTask {
+    pub index_uid: IndexUid,
+    pub delete_query: DeleteQuery,
+}
+
+#[async_trait]
+impl Handler<CreateDeleteTask> for DeleteTaskService {
+    type Reply = anyhow::Result<u64>;
+
+    async fn handle(
+        &mut self,
+        message: CreateDeleteTask,
+        _ctx: &ActorContext<Self>,
+    ) -> Result<Self::Reply, ActorExitStatus> {
+        let reply = async {
+            // Goes through the event-publishing decorator rather than `self.metastore` directly,
+            // so this call publishes `DeleteTaskCreatedEvent` through the same wrapper as any other
+            // `MetastoreEventPublisher` caller, instead of this handler publishing it by hand as a
+            // one-off.
+            let delete_task = self
+                .delete_task_event_publisher
+                .create_delete_task(message.delete_query)
+                .await?;
+            let now = OffsetDateTime::now_utc().unix_timestamp();
+            self.delete_task_statuses
+                .entry((message.index_uid, delete_task.opstamp))
+                .or_insert_with(|| DeleteTaskStatusInfo::new(now));
+            Ok(delete_task.opstamp)
+        }
+        .await;
+        Ok(reply)
+    }
+}
+
+/// Request to cancel a delete task that is either still enqueued or currently being applied by
+/// its pipeline.
+#[derive(Debug)]
+pub struct CancelDeleteTask {
+    pub index_uid: IndexUid,
+    pub opstamp: u64,
+}
+
+#[async_trait]
+impl Handler<CancelDeleteTask> for DeleteTaskService {
+    type Reply = ();
+
+    async fn handle(
+        &mut self,
+        message: CancelDeleteTask,
+        _ctx: &ActorContext<Self>,
+    ) -> Result<(), ActorExitStatus> {
+        if message.opstamp & PREVIEW_TASK_ID_BIT != 0 {
+            // A preview id is never backed by a metastore opstamp, so there is nothing to cancel:
+            // `delete_delete_tasks` below would be a pointless call, and flipping its (already
+            // terminal, almost by the time anyone could ask) status to `Cancelled` would make a
+            // finished preview look like a real task that got cut short.
+            warn!(
+                index_uid=%message.index_uid,
+                opstamp = message.opstamp,
+                "ignoring cancellation of a delete query preview task"
+            );
+            return Ok(());
+        }
+        // A `ReportDeleteTaskStatus` reporting a terminal outcome can land in this actor's
+        // mailbox before or while this very message is being processed, if the pipeline
+        // finished the task right as the cancellation was requested. Once that status is
+        // terminal, it reflects what genuinely happened to the task, so it must win: clobbering
+        // it with `Cancelled` (and deleting the metastore record below) would make a delete that
+        // actually succeeded or failed look like it never ran.
+        let existing_status = self.delete_task_status(&message.index_uid, message.opstamp);
+        if let Some(status_info) = &existing_status {
+            if status_info.status.is_terminal() {
+                warn!(
+                    index_uid=%message.index_uid,
+                    opstamp = message.opstamp,
+                    status = ?status_info.status,
+                    "ignoring cancellation of an already-terminal delete task"
+                );
+                return Ok(());
+            }
+        }
+        let needs_abort_signal =
+            needs_pipeline_abort_signal(existing_status.as_ref().map(|info| &info.status));
+
+        // Record the cancellation immediately so reconciliation (and anyone polling the task
+        // status) sees it right away, regardless of whether the pipeline is currently running.
+        self.record_delete_task_status(
+            message.index_uid.clone(),
+            message.opstamp,
+            DeleteTaskStatus::Cancelled,
+        );
+
+        // Persist the cancellation by removing the task from the metastore: a cancelled task
+        // that is only marked in our in-memory map would be re-applied by any pipeline that
+        // comes back up after a restart (or simply once this map evicts the entry).
+        if let Err(error) = self
+            .metastore
+            .delete_delete_tasks(DeleteDeleteTasksRequest {
+                index_uid: message.index_uid.to_string(),
+                opstamps: vec![message.opstamp],
+            })
+            .await
+        {
+            error!(
+                error=%error,
+                index_uid=%message.index_uid,
+                opstamp = message.opstamp,
+                "failed to persist delete task cancellation to the metastore"
+            );
+        }
+
+        let Some(pipeline_handle) = self.pipeline_handles_by_index_uid.get(&message.index_uid)
+        else {
+            // The index has no running pipeline (e.g. it was just deleted). The task is still
+            // marked cancelled above, so it won't be picked up again.
+            warn!(
+                index_uid=%message.index_uid,
+                opstamp = message.opstamp,
+                "no running delete task pipeline for index, cancellation recorded only"
+            );
+            return Ok(());
+        };
+        // Skipped for a still-enqueued task: see `needs_pipeline_abort_signal` and the pipeline's
+        // own `aborted_opstamps` field doc for why this is a shared `Mutex` rather than a message.
+        if needs_abort_signal {
+            pipeline_handle
+                .aborted_opstamps
+                .lock()
+                .unwrap()
+                .insert(message.opstamp);
+        }
+        Ok(())
+    }
+}
+
+/// Whether cancelling a delete task currently in `status` requires signalling its pipeline
+/// through `aborted_opstamps`, as opposed to relying solely on the metastore deletion that
+/// `Handler<CancelDeleteTask>` always performs. Only a task the pipeline has actually started
+/// applying (`Processing`) needs this: a task that is still enqueued (or not tracked at all) has,
+/// by definition, not reached the pipeline's `aborted_opstamps` consumption loop yet, so inserting
+/// its opstamp anyway would leak it in that set forever — the pipeline only ever removes an
+/// opstamp it sees in `list_delete_tasks`, and the deleted row will never appear there again.
+fn needs_pipeline_abort_signal(status: Option<&DeleteTaskStatus>) -> bool {
+    matches!(status, Some(DeleteTaskStatus::Processing))
+}
+
+#[async_trait]
+impl Handler<IndexCreatedEvent> for DeleteTaskService {
+    type Reply = ();
+
+    async fn handle(
+        &mut self,
+        event: IndexCreatedEvent,
+        ctx: &ActorContext<Self>,
+    ) -> Result<(), ActorExitStatus> {
+        if let Err(error) = self
+            .spawn_pipeline_for_index_uid(&event.index_uid, ctx)
+            .await
+        {
+            warn!(
+                error=%error,
+                index_uid=%event.index_uid,
+                "failed to spawn delete task pipeline for newly created index"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<IndexDeletedEvent> for DeleteTaskService {
+    type Reply = ();
+
+    async fn handle(
+        &mut self,
+        event: IndexDeletedEvent,
+        _ctx: &ActorContext<Self>,
+    ) -> Result<(), ActorExitStatus> {
+        self.remove_pipeline(&event.index_uid).await;
+        Ok(())
+    }
+}
+
+/// Nudges a pipeline to immediately check the metastore for newly created delete tasks, instead
+/// of waiting for its own internal polling loop.
+#[derive(Debug)]
+pub struct WakeUpDeleteTaskPipeline;
+
+#[async_trait]
+impl Handler<DeleteTaskCreatedEvent> for DeleteTaskService {
+    type Reply = ();
+
+    async fn handle(
+        &mut self,
+        event: DeleteTaskCreatedEvent,
+        _ctx: &ActorContext<Self>,
+    ) -> Result<(), ActorExitStatus> {
+        // `CreateDeleteTask` already records this for tasks created through this actor; this
+        // covers a task created through some other path that still reaches `event_broker`, so an
+        // `Enqueued` status is never missing just because of which door a task came in through.
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        self.delete_task_statuses
+            .entry((event.index_uid.clone(), event.opstamp))
+            .or_insert_with(|| DeleteTaskStatusInfo::new(now));
+
+        let Some(pipeline_handle) = self.pipeline_handles_by_index_uid.get(&event.index_uid) else {
+            // No pipeline yet for this index; the next `UpdatePipelines` reconciliation (or the
+            // `IndexCreatedEvent` that should already have fired) will spawn one, and it will
+            // pick up the delete task as part of its own startup.
+            return Ok(());
+        };
+        if let Err(error) = pipeline_handle
+            .actor_handle
+            .mailbox()
+            .send_message(WakeUpDeleteTaskPipeline)
+            .await
+        {
+            error!(
+                error=%error,
+                index_uid=%event.index_uid,
+                "failed to wake up delete task pipeline for newly created delete task"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Requests a dry-run of a delete query: no split is rewritten, only the number of documents and
+/// splits the query would affect is computed. Replies right away with the id of the preview
+/// task; the preview itself runs in the background, and its eventual status can be polled with
+/// [`GetDeleteTaskStatus`] using that id.
+#[derive(Debug)]
+pub struct PreviewDeleteQuery {
+    pub index_uid: IndexUid,
+    pub delete_query: DeleteQuery,
+}
+
+#[async_trait]
+impl Handler<PreviewDeleteQuery> for DeleteTaskService {
+    type Reply = u64;
+
+    async fn handle(
+        &mut self,
+        message: PreviewDeleteQuery,
+        ctx: &ActorContext<Self>,
+    ) -> Result<u64, ActorExitStatus> {
+        let preview_task_id = self.next_preview_task_id();
+        self.record_delete_task_status(
+            message.index_uid.clone(),
+            preview_task_id,
+            DeleteTaskStatus::Processing,
+        );
+
+        // Resolving candidate splits and counting matches involves a leaf search scan over the
+        // index's splits, which can be expensive: run it off this actor's own mailbox so a slow
+        // preview can't block index/delete-task event handling or other previews in the meantime.
+        // The result is reported back asynchronously, the same way `DeleteTaskPipeline` reports
+        // the status of a real delete task it applied.
+        let mailbox = ctx.mailbox().clone();
+        let metastore = self.metastore.clone();
+        let search_job_placer = self.search_job_placer.clone();
+        let storage_resolver = self.storage_resolver.clone();
+        tokio::spawn(async move {
+            let mut metastore = metastore;
+            // Reuse the same split resolution and leaf-count machinery the pipeline relies on to
+            // find the splits it needs to rewrite, but stop short of actually rewriting anything.
+            let preview_result = delete_task_pipeline::preview_matching_splits(
+                &message.index_uid,
+                &message.delete_query,
+                &mut metastore,
+                &search_job_placer,
+                &storage_resolver,
+            )
+            .await;
+            let status = match preview_result {
+                Ok(counts) => DeleteTaskStatus::Previewed {
+                    total_matched_docs: counts.total_matched_docs,
+                    matched_docs_by_split_id: counts.matched_docs_by_split_id,
+                    split_ids_to_rewrite: counts.split_ids_to_rewrite,
+                },
+                Err(error) => DeleteTaskStatus::Failed {
+                    error: error.to_string(),
+                },
+            };
+            if let Err(error) = mailbox
+                .send_message(ReportDeleteTaskStatus {
+                    index_uid: message.index_uid,
+                    opstamp: preview_task_id,
+                    status,
+                })
+                .await
+            {
+                error!(error=%error, preview_task_id, "failed to report delete query preview status");
+            }
+        });
+        Ok(preview_task_id)
+    }
+}
+
+/// Looks up the current status of a delete task by its opstamp (or, for a preview, by the
+/// synthetic id returned from [`PreviewDeleteQuery`]), scoped to `index_uid`. Unlike
+/// `ListDeleteTasksRequest`, this also covers preview ids, which never appear in the metastore.
+#[derive(Debug)]
+pub struct GetDeleteTaskStatus {
+    pub index_uid: IndexUid,
+    pub opstamp: u64,
+}
+
+#[async_trait]
+impl Handler<GetDeleteTaskStatus> for DeleteTaskService {
+    type Reply = Option<DeleteTaskStatusInfo>;
+
+    async fn handle(
+        &mut self,
+        message: GetDeleteTaskStatus,
+        _ctx: &ActorContext<Self>,
+    ) -> Result<Self::Reply, ActorExitStatus> {
+        Ok(self.delete_task_status(&message.index_uid, message.opstamp))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use quickwit_common::pubsub::EventBroker;
     use quickwit_indexing::TestSandbox;
+    use quickwit_metastore::metastore_event_publisher::MetastoreEventPublisher;
+    use quickwit_metastore::{ListSplitsRequestExt, ListSplitsResponseExt};
     use quickwit_proto::metastore::{
-        DeleteIndexRequest, DeleteQuery, ListDeleteTasksRequest, MetastoreService,
+        DeleteIndexRequest, DeleteQuery, ListDeleteTasksRequest, ListSplitsRequest,
+        MetastoreService,
     };
     use quickwit_search::{searcher_pool_for_test, MockSearchService, SearchJobPlacer};
     use quickwit_storage::StorageResolver;
 
-    use super::{DeleteTaskService, UPDATE_PIPELINES_INTERVAL};
+    use super::{
+        needs_pipeline_abort_signal, CancelDeleteTask, CreateDeleteTask, DeleteTaskService,
+        DeleteTaskStatus, GetDeleteTaskStatus, PreviewDeleteQuery, ReportDeleteTaskStatus,
+        UPDATE_PIPELINES_INTERVAL,
+    };
 
-    #[tokio::test]
-    async fn test_delete_task_service() -> anyhow::Result<()> {
-        quickwit_common::setup_logging_for_tests();
-        let index_id = "test-delete-task-service-index";
-        let doc_mapping_yaml = r#"
-            field_mappings:
-              - name: body
-                type: text
-              - name: ts
-                type: i64
-                fast: true
-        "#;
-        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
-        let index_uid = test_sandbox.index_uid();
-        let mut metastore = test_sandbox.metastore();
+    #[test]
+    fn test_needs_pipeline_abort_signal_only_for_processing_tasks() {
+        assert!(!needs_pipeline_abort_signal(None));
+        assert!(!needs_pipeline_abort_signal(Some(&DeleteTaskStatus::Enqueued)));
+        assert!(needs_pipeline_abort_signal(Some(&DeleteTaskStatus::Processing)));
+        assert!(!needs_pipeline_abort_signal(Some(&DeleteTaskStatus::Cancelled)));
+    }
+
+    async fn spawn_test_delete_task_service(
+        test_sandbox: &TestSandbox,
+        event_broker: EventBroker,
+    ) -> quickwit_actors::ActorHandle<DeleteTaskService> {
+        let metastore = test_sandbox.metastore();
         let mock_search_service = MockSearchService::new();
         let searcher_pool = searcher_pool_for_test([("127.0.0.1:1000", mock_search_service)]);
         let search_job_placer = SearchJobPlacer::new(searcher_pool);
         let temp_dir = tempfile::tempdir().unwrap();
-        let data_dir_path = temp_dir.path().to_path_buf();
         let delete_task_service = DeleteTaskService::new(
-            metastore.clone(),
+            metastore,
             search_job_placer,
             StorageResolver::unconfigured(),
-            data_dir_path,
+            temp_dir.path().to_path_buf(),
             4,
-            EventBroker::default(),
+            event_broker,
         )
         .await
         .unwrap();
-        let (_delete_task_service_mailbox, delete_task_service_handler) = test_sandbox
+        let (_mailbox, handler) = test_sandbox
             .universe()
             .spawn_builder()
             .spawn(delete_task_service);
+        handler
+    }
+
+    #[tokio::test]
+    async fn test_delete_task_service() -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-delete-task-service-index";
+        let doc_mapping_yaml = r#"
+            field_mappings:
+              - name: body
+                type: text
+              - name: ts
+                type: i64
+                fast: true
+        "#;
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let event_broker = EventBroker::default();
+        // Index events are only published through this decorator: wrapping the sandbox metastore
+        // here is what makes the `delete_index` call below publish an `IndexDeletedEvent`.
+        let mut event_publishing_metastore =
+            MetastoreEventPublisher::new(test_sandbox.metastore(), event_broker.clone());
+        let delete_task_service_handler =
+            spawn_test_delete_task_service(&test_sandbox, event_broker).await;
         let state = delete_task_service_handler
             .process_pending_and_observe()
             .await;
@@ -266,10 +984,14 @@ mod tests {
             end_timestamp: None,
             query_ast: r#"{"type": "MatchAll"}"#.to_string(),
         };
-        metastore.create_delete_task(delete_query).await.unwrap();
+        event_publishing_metastore
+            .create_delete_task(delete_query)
+            .await
+            .unwrap();
         // Just test creation of delete query.
         assert_eq!(
-            metastore
+            test_sandbox
+                .metastore()
                 .list_delete_tasks(ListDeleteTasksRequest::new(index_uid.clone(), 0))
                 .await
                 .unwrap()
@@ -277,15 +999,18 @@ mod tests {
                 .len(),
             1
         );
-        metastore
+        event_publishing_metastore
             .delete_index(DeleteIndexRequest {
                 index_uid: index_uid.to_string(),
             })
             .await
             .unwrap();
+        // The `IndexDeletedEvent` published by `event_publishing_metastore` above should tear
+        // down the pipeline right away: no need to wait anywhere near a full
+        // `UPDATE_PIPELINES_INTERVAL`, unlike when this only happened through polling.
         test_sandbox
             .universe()
-            .sleep(UPDATE_PIPELINES_INTERVAL * 2)
+            .sleep(Duration::from_millis(50))
             .await;
         let state_after_deletion = delete_task_service_handler
             .process_pending_and_observe()
@@ -312,4 +1037,532 @@ mod tests {
         test_sandbox.assert_quit().await;
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_create_delete_task_records_enqueued_status() -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-create-delete-task-enqueued-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let delete_task_service_handler =
+            spawn_test_delete_task_service(&test_sandbox, EventBroker::default()).await;
+        delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+
+        let delete_query = DeleteQuery {
+            index_uid: index_uid.to_string(),
+            start_timestamp: None,
+            end_timestamp: None,
+            query_ast: r#"{"type": "MatchAll"}"#.to_string(),
+        };
+        let opstamp = delete_task_service_handler
+            .mailbox()
+            .ask(CreateDeleteTask {
+                index_uid: index_uid.clone(),
+                delete_query,
+            })
+            .await?
+            .unwrap();
+        // Unlike a task created directly against the metastore, one created through this message
+        // is tracked as `Enqueued` right away, before its pipeline has had any chance to report
+        // `Processing`.
+        let state = delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+        assert_eq!(
+            state
+                .delete_task_statuses
+                .get(&(index_uid.clone(), opstamp))
+                .unwrap()
+                .status,
+            DeleteTaskStatus::Enqueued
+        );
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_task_status_lifecycle() -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-delete-task-status-lifecycle-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let delete_task_service_handler =
+            spawn_test_delete_task_service(&test_sandbox, EventBroker::default()).await;
+        delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+
+        let opstamp = 42;
+        delete_task_service_handler
+            .mailbox()
+            .send_message(ReportDeleteTaskStatus {
+                index_uid: index_uid.clone(),
+                opstamp,
+                status: DeleteTaskStatus::Processing,
+            })
+            .await?;
+        let state = delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+        let status_info = state
+            .delete_task_statuses
+            .get(&(index_uid.clone(), opstamp))
+            .unwrap();
+        assert_eq!(status_info.status, DeleteTaskStatus::Processing);
+        assert!(status_info.started_at.is_some());
+        assert!(status_info.finished_at.is_none());
+
+        delete_task_service_handler
+            .mailbox()
+            .send_message(ReportDeleteTaskStatus {
+                index_uid: index_uid.clone(),
+                opstamp,
+                status: DeleteTaskStatus::Succeeded {
+                    num_matched_docs: 10,
+                    num_splits_marked: 2,
+                },
+            })
+            .await?;
+        let state = delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+        let status_info = state
+            .delete_task_statuses
+            .get(&(index_uid.clone(), opstamp))
+            .unwrap();
+        assert!(matches!(
+            status_info.status,
+            DeleteTaskStatus::Succeeded {
+                num_matched_docs: 10,
+                num_splits_marked: 2
+            }
+        ));
+        assert!(status_info.finished_at.is_some());
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_delete_task_persists_to_metastore() -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-cancel-delete-task-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let mut metastore = test_sandbox.metastore();
+        let delete_task_service_handler =
+            spawn_test_delete_task_service(&test_sandbox, EventBroker::default()).await;
+        delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+
+        let delete_query = DeleteQuery {
+            index_uid: index_uid.to_string(),
+            start_timestamp: None,
+            end_timestamp: None,
+            query_ast: r#"{"type": "MatchAll"}"#.to_string(),
+        };
+        let delete_task = metastore.create_delete_task(delete_query).await.unwrap();
+
+        delete_task_service_handler
+            .mailbox()
+            .send_message(CancelDeleteTask {
+                index_uid: index_uid.clone(),
+                opstamp: delete_task.opstamp,
+            })
+            .await?;
+        let state = delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+        assert_eq!(
+            state
+                .delete_task_statuses
+                .get(&(index_uid.clone(), delete_task.opstamp))
+                .unwrap()
+                .status,
+            DeleteTaskStatus::Cancelled
+        );
+        // Persisted: the metastore no longer has the task, so a restart can't re-apply it.
+        assert_eq!(
+            metastore
+                .list_delete_tasks(ListDeleteTasksRequest::new(index_uid.clone(), 0))
+                .await
+                .unwrap()
+                .delete_tasks
+                .len(),
+            0
+        );
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_delete_task_does_not_clobber_already_succeeded_status(
+    ) -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-cancel-already-succeeded-delete-task-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let mut metastore = test_sandbox.metastore();
+        let delete_task_service_handler =
+            spawn_test_delete_task_service(&test_sandbox, EventBroker::default()).await;
+        delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+
+        let delete_query = DeleteQuery {
+            index_uid: index_uid.to_string(),
+            start_timestamp: None,
+            end_timestamp: None,
+            query_ast: r#"{"type": "MatchAll"}"#.to_string(),
+        };
+        let delete_task = metastore.create_delete_task(delete_query).await.unwrap();
+
+        // Simulate the pipeline reporting the task as finished right before the cancellation is
+        // processed, i.e. the race the cancellation handler must not lose.
+        delete_task_service_handler
+            .mailbox()
+            .send_message(ReportDeleteTaskStatus {
+                index_uid: index_uid.clone(),
+                opstamp: delete_task.opstamp,
+                status: DeleteTaskStatus::Succeeded {
+                    num_matched_docs: 10,
+                    num_splits_marked: 2,
+                },
+            })
+            .await?;
+        delete_task_service_handler
+            .mailbox()
+            .send_message(CancelDeleteTask {
+                index_uid: index_uid.clone(),
+                opstamp: delete_task.opstamp,
+            })
+            .await?;
+        let state = delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+        assert!(matches!(
+            state
+                .delete_task_statuses
+                .get(&(index_uid.clone(), delete_task.opstamp))
+                .unwrap()
+                .status,
+            DeleteTaskStatus::Succeeded {
+                num_matched_docs: 10,
+                num_splits_marked: 2
+            }
+        ));
+        // Not persisted away: the task really did succeed, so the metastore record must survive.
+        assert_eq!(
+            metastore
+                .list_delete_tasks(ListDeleteTasksRequest::new(index_uid.clone(), 0))
+                .await
+                .unwrap()
+                .delete_tasks
+                .len(),
+            1
+        );
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_late_report_does_not_clobber_already_cancelled_status() -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-late-report-after-cancel-delete-task-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let mut metastore = test_sandbox.metastore();
+        let delete_task_service_handler =
+            spawn_test_delete_task_service(&test_sandbox, EventBroker::default()).await;
+        delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+
+        let delete_query = DeleteQuery {
+            index_uid: index_uid.to_string(),
+            start_timestamp: None,
+            end_timestamp: None,
+            query_ast: r#"{"type": "MatchAll"}"#.to_string(),
+        };
+        let delete_task = metastore.create_delete_task(delete_query).await.unwrap();
+
+        delete_task_service_handler
+            .mailbox()
+            .send_message(CancelDeleteTask {
+                index_uid: index_uid.clone(),
+                opstamp: delete_task.opstamp,
+            })
+            .await?;
+        // Simulate the pipeline's `apply_pending_delete_tasks` having already passed its
+        // `task_still_pending` recheck before the cancellation above landed, and only now
+        // reporting the outcome it had already committed to: the reverse of the race covered by
+        // `test_cancel_delete_task_does_not_clobber_already_succeeded_status`.
+        delete_task_service_handler
+            .mailbox()
+            .send_message(ReportDeleteTaskStatus {
+                index_uid: index_uid.clone(),
+                opstamp: delete_task.opstamp,
+                status: DeleteTaskStatus::Succeeded {
+                    num_matched_docs: 10,
+                    num_splits_marked: 2,
+                },
+            })
+            .await?;
+        let state = delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+        assert_eq!(
+            state
+                .delete_task_statuses
+                .get(&(index_uid.clone(), delete_task.opstamp))
+                .unwrap()
+                .status,
+            DeleteTaskStatus::Cancelled
+        );
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_preview_delete_query_does_not_mutate_splits() -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-preview-delete-query-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let metastore = test_sandbox.metastore();
+        let splits_before: Vec<(String, u64)> = metastore
+            .clone()
+            .list_splits(ListSplitsRequest::try_from_index_uid(index_uid.clone()))
+            .await?
+            .deserialize_splits()?
+            .into_iter()
+            .map(|split| {
+                (
+                    split.split_metadata.split_id().to_string(),
+                    split.split_metadata.delete_opstamp,
+                )
+            })
+            .collect();
+        let delete_task_service_handler =
+            spawn_test_delete_task_service(&test_sandbox, EventBroker::default()).await;
+        delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+
+        let delete_query = DeleteQuery {
+            index_uid: index_uid.to_string(),
+            start_timestamp: None,
+            end_timestamp: None,
+            query_ast: r#"{"type": "MatchAll"}"#.to_string(),
+        };
+        let preview_task_id = delete_task_service_handler
+            .mailbox()
+            .ask(PreviewDeleteQuery {
+                index_uid: index_uid.clone(),
+                delete_query,
+            })
+            .await?;
+        // The preview runs in the background, so reaching a terminal status can take more than
+        // one tick: poll `GetDeleteTaskStatus` instead of assuming a single
+        // `process_pending_and_observe()` is enough, the way a real caller would.
+        let mut status_info = None;
+        for _ in 0..50 {
+            let info = delete_task_service_handler
+                .mailbox()
+                .ask(GetDeleteTaskStatus {
+                    index_uid: index_uid.clone(),
+                    opstamp: preview_task_id,
+                })
+                .await?;
+            if matches!(&info, Some(info) if info.status.is_terminal()) {
+                status_info = info;
+                break;
+            }
+            test_sandbox
+                .universe()
+                .sleep(Duration::from_millis(20))
+                .await;
+        }
+        let status_info = status_info.expect("preview did not reach a terminal status in time");
+        assert!(matches!(
+            status_info.status,
+            DeleteTaskStatus::Previewed { .. } | DeleteTaskStatus::Failed { .. }
+        ));
+        // A preview is read-only: the candidate splits and their delete opstamps must be exactly
+        // what they were before, since nothing here goes through `update_splits_delete_opstamp`.
+        let splits_after: Vec<(String, u64)> = metastore
+            .clone()
+            .list_splits(ListSplitsRequest::try_from_index_uid(index_uid.clone()))
+            .await?
+            .deserialize_splits()?
+            .into_iter()
+            .map(|split| {
+                (
+                    split.split_metadata.split_id().to_string(),
+                    split.split_metadata.delete_opstamp,
+                )
+            })
+            .collect();
+        assert_eq!(splits_before, splits_after);
+        // A preview is synthesized locally and never backed by a metastore delete task.
+        assert_eq!(
+            metastore
+                .list_delete_tasks(ListDeleteTasksRequest::new(index_uid.clone(), 0))
+                .await?
+                .delete_tasks
+                .len(),
+            0
+        );
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_delete_task_ignores_preview_task_ids() -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let index_id = "test-cancel-preview-delete-task-index";
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox = TestSandbox::create(index_id, doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid = test_sandbox.index_uid();
+        let delete_task_service_handler =
+            spawn_test_delete_task_service(&test_sandbox, EventBroker::default()).await;
+        delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+
+        let delete_query = DeleteQuery {
+            index_uid: index_uid.to_string(),
+            start_timestamp: None,
+            end_timestamp: None,
+            query_ast: r#"{"type": "MatchAll"}"#.to_string(),
+        };
+        let preview_task_id = delete_task_service_handler
+            .mailbox()
+            .ask(PreviewDeleteQuery {
+                index_uid: index_uid.clone(),
+                delete_query,
+            })
+            .await?;
+        // Wait for the preview to reach a terminal status before trying to cancel it, so the
+        // preview's own background report cannot change `status_before` after the cancellation.
+        // Once terminal, the already-terminal guard would leave the status untouched as well, so
+        // the assertion below checks the outcome, not which of the two guards produced it.
+        let mut status_info = None;
+        for _ in 0..50 {
+            let info = delete_task_service_handler
+                .mailbox()
+                .ask(GetDeleteTaskStatus {
+                    index_uid: index_uid.clone(),
+                    opstamp: preview_task_id,
+                })
+                .await?;
+            if matches!(&info, Some(info) if info.status.is_terminal()) {
+                status_info = info;
+                break;
+            }
+            test_sandbox
+                .universe()
+                .sleep(Duration::from_millis(20))
+                .await;
+        }
+        let status_before = status_info
+            .expect("preview did not reach a terminal status in time")
+            .status;
+
+        delete_task_service_handler
+            .mailbox()
+            .send_message(CancelDeleteTask {
+                index_uid: index_uid.clone(),
+                opstamp: preview_task_id,
+            })
+            .await?;
+        let state = delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+        // Untouched: a preview id is never a real delete task, so cancelling it must be a no-op.
+        assert_eq!(
+            state
+                .preview_statuses
+                .get(&(index_uid.clone(), preview_task_id))
+                .unwrap()
+                .status,
+            status_before
+        );
+        test_sandbox.assert_quit().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_task_statuses_do_not_clobber_across_indexes() -> anyhow::Result<()> {
+        quickwit_common::setup_logging_for_tests();
+        let doc_mapping_yaml = "field_mappings:\n  - name: body\n    type: text\n";
+        let test_sandbox_a =
+            TestSandbox::create("test-clobber-index-a", doc_mapping_yaml, "{}", &["body"]).await?;
+        let test_sandbox_b =
+            TestSandbox::create("test-clobber-index-b", doc_mapping_yaml, "{}", &["body"]).await?;
+        let index_uid_a = test_sandbox_a.index_uid();
+        let index_uid_b = test_sandbox_b.index_uid();
+        let delete_task_service_handler =
+            spawn_test_delete_task_service(&test_sandbox_a, EventBroker::default()).await;
+        delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+
+        // Both indexes report a status for the exact same opstamp, as two independent delete task
+        // opstamp sequences naturally would.
+        let opstamp = 1;
+        delete_task_service_handler
+            .mailbox()
+            .send_message(ReportDeleteTaskStatus {
+                index_uid: index_uid_a.clone(),
+                opstamp,
+                status: DeleteTaskStatus::Succeeded {
+                    num_matched_docs: 5,
+                    num_splits_marked: 1,
+                },
+            })
+            .await?;
+        delete_task_service_handler
+            .mailbox()
+            .send_message(ReportDeleteTaskStatus {
+                index_uid: index_uid_b.clone(),
+                opstamp,
+                status: DeleteTaskStatus::Failed {
+                    error: "boom".to_string(),
+                },
+            })
+            .await?;
+        let state = delete_task_service_handler
+            .process_pending_and_observe()
+            .await;
+
+        let status_a = &state
+            .delete_task_statuses
+            .get(&(index_uid_a.clone(), opstamp))
+            .unwrap()
+            .status;
+        let status_b = &state
+            .delete_task_statuses
+            .get(&(index_uid_b.clone(), opstamp))
+            .unwrap()
+            .status;
+        assert!(matches!(
+            status_a,
+            DeleteTaskStatus::Succeeded {
+                num_matched_docs: 5,
+                num_splits_marked: 1
+            }
+        ));
+        assert!(matches!(status_b, DeleteTaskStatus::Failed { .. }));
+        test_sandbox_a.assert_quit().await;
+        Ok(())
+    }
 }
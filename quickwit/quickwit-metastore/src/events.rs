@@ -0,0 +1,47 @@
+// Copyright (C) 2024 Quickwit, Inc.
+//
+// Quickwit is offered under the AGPL v3.0 and as commercial software.
+// For commercial licensing, contact us at hello@quickwit.io.
+//
+// AGPL:
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as
+// published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use quickwit_common::pubsub::Event;
+use quickwit_proto::types::IndexUid;
+
+/// Published once an index has been created and is visible in the metastore.
+#[derive(Debug, Clone)]
+pub struct IndexCreatedEvent {
+    pub index_uid: IndexUid,
+}
+
+impl Event for IndexCreatedEvent {}
+
+/// Published once an index has been deleted from the metastore.
+#[derive(Debug, Clone)]
+pub struct IndexDeletedEvent {
+    pub index_uid: IndexUid,
+}
+
+impl Event for IndexDeletedEvent {}
+
+/// Published once a delete task has been created for an index, so interested parties (e.g. the
+/// index's `DeleteTaskPipeline`) don't have to wait for their next poll to learn about it.
+#[derive(Debug, Clone)]
+pub struct DeleteTaskCreatedEvent {
+    pub index_uid: IndexUid,
+    pub opstamp: u64,
+}
+
+impl Event for DeleteTaskCreatedEvent {}
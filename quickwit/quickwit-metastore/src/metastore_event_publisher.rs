@@ -0,0 +1,86 @@
+// Copyright (C) 2024 Quickwit, Inc.
+//
+// Quickwit is offered under the AGPL v3.0 and as commercial software.
+// For commercial licensing, contact us at hello@quickwit.io.
+//
+// AGPL:
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as
+// published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use quickwit_common::pubsub::EventBroker;
+use quickwit_proto::metastore::{
+    CreateIndexRequest, CreateIndexResponse, DeleteIndexRequest, DeleteQuery, DeleteTask,
+    EmptyResponse, MetastoreResult, MetastoreService, MetastoreServiceClient,
+};
+use quickwit_proto::types::IndexUid;
+
+use crate::events::{DeleteTaskCreatedEvent, IndexCreatedEvent, IndexDeletedEvent};
+
+/// Thin wrapper around a [`MetastoreServiceClient`] that publishes an event on the given
+/// [`EventBroker`] once an index or delete task mutation has actually been durably recorded.
+///
+/// It intentionally only wraps the handful of calls that have a corresponding event: every other
+/// metastore call should keep going straight through the unwrapped client.
+///
+/// `quickwit-janitor`'s `DeleteTaskService` only routes `create_delete_task` through this wrapper;
+/// `create_index`/`delete_index` are not called through it by the production metastore client the
+/// rest of the server uses -- see `UPDATE_PIPELINES_INTERVAL`'s doc comment in
+/// `delete_task_service.rs` for which of this wrapper's calls are actually reachable in
+/// production today.
+#[derive(Clone)]
+pub struct MetastoreEventPublisher {
+    metastore: MetastoreServiceClient,
+    event_broker: EventBroker,
+}
+
+impl MetastoreEventPublisher {
+    pub fn new(metastore: MetastoreServiceClient, event_broker: EventBroker) -> Self {
+        Self {
+            metastore,
+            event_broker,
+        }
+    }
+
+    pub async fn create_index(
+        &mut self,
+        request: CreateIndexRequest,
+    ) -> MetastoreResult<CreateIndexResponse> {
+        let response = self.metastore.create_index(request).await?;
+        let index_uid: IndexUid = response.index_uid().clone();
+        self.event_broker.publish(IndexCreatedEvent { index_uid });
+        Ok(response)
+    }
+
+    pub async fn delete_index(
+        &mut self,
+        request: DeleteIndexRequest,
+    ) -> MetastoreResult<EmptyResponse> {
+        let index_uid: IndexUid = request.index_uid.clone().into();
+        let response = self.metastore.delete_index(request).await?;
+        self.event_broker.publish(IndexDeletedEvent { index_uid });
+        Ok(response)
+    }
+
+    pub async fn create_delete_task(
+        &mut self,
+        delete_query: DeleteQuery,
+    ) -> MetastoreResult<DeleteTask> {
+        let index_uid: IndexUid = delete_query.index_uid.clone().into();
+        let delete_task = self.metastore.create_delete_task(delete_query).await?;
+        self.event_broker.publish(DeleteTaskCreatedEvent {
+            index_uid,
+            opstamp: delete_task.opstamp,
+        });
+        Ok(delete_task)
+    }
+}